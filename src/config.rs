@@ -0,0 +1,229 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// User-tunable settings for rofi itself, menu layout, sort order, and the
+/// background-refresh cache TTL, read from `~/.config/rofi-steam/config.toml`.
+/// Missing or unparseable values fall back to [`Config::default`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub rofi_args: Vec<String>,
+    pub rofi_prompt: String,
+    pub rofi_monitor: Option<String>,
+    pub display_width: usize,
+    pub hours_col_width: usize,
+    pub status_col_width: usize,
+    pub sort_mode: SortMode,
+    pub cache_ttl_secs: u64,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SortMode {
+    LastPlayed,
+    Playtime,
+    Name,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            rofi_args: vec![String::from("-i"), String::from("-dmenu"), String::from("-sync")],
+            rofi_prompt: String::from("launch"),
+            rofi_monitor: Some(String::from("1")),
+            display_width: 125,
+            hours_col_width: 8,
+            status_col_width: 20,
+            sort_mode: SortMode::LastPlayed,
+            cache_ttl_secs: 6 * 60 * 60,
+        }
+    }
+}
+
+fn config_dir() -> PathBuf {
+    let home = env::var("HOME").unwrap_or_else(|_| String::from("~"));
+    PathBuf::from(home).join(".config/rofi-steam")
+}
+
+impl Config {
+    pub fn load() -> Config {
+        match fs::read_to_string(config_dir().join("config.toml")) {
+            Ok(content) => parse_config(&content),
+            Err(_) => Config::default(),
+        }
+    }
+}
+
+/// Loads the per-line ignore list (`~/.config/rofi-steam/ignore`): each
+/// non-empty, non-comment line is either a bare Steam appid or a `*`-glob
+/// matched case-insensitively against a game's name.
+pub fn load_ignore_list() -> Vec<String> {
+    let content = match fs::read_to_string(config_dir().join("ignore")) {
+        Ok(content) => content,
+        Err(_) => return Vec::new(),
+    };
+
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+pub fn matches_glob(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.to_ascii_lowercase();
+    let text = text.to_ascii_lowercase();
+
+    if !pattern.contains('*') {
+        return pattern == text;
+    }
+
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let mut pos = 0;
+    for (i, part) in parts.iter().enumerate() {
+        if part.is_empty() {
+            continue;
+        }
+        if i == 0 {
+            if !text[pos..].starts_with(part) {
+                return false;
+            }
+            pos += part.len();
+        } else if i == parts.len() - 1 {
+            return text[pos..].ends_with(part);
+        } else {
+            match text[pos..].find(part) {
+                Some(found) => pos += found + part.len(),
+                None => return false,
+            }
+        }
+    }
+    true
+}
+
+/// A minimal `[section]` / `key = value` reader covering just the shapes our
+/// own config file uses (strings, bare words, ints, and `["a", "b"]` string
+/// arrays) — not a general TOML parser.
+fn parse_config(content: &str) -> Config {
+    let mut config = Config::default();
+    let mut section = String::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            section = line[1..line.len() - 1].trim().to_string();
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim();
+
+        match (section.as_str(), key) {
+            ("rofi", "args") => config.rofi_args = parse_string_array(value),
+            ("rofi", "prompt") => config.rofi_prompt = unquote(value),
+            ("rofi", "monitor") => config.rofi_monitor = Some(unquote(value)),
+            ("display", "width") => {
+                if let Ok(n) = value.parse() {
+                    config.display_width = n;
+                }
+            }
+            ("display", "hours_col_width") => {
+                if let Ok(n) = value.parse() {
+                    config.hours_col_width = n;
+                }
+            }
+            ("display", "status_col_width") => {
+                if let Ok(n) = value.parse() {
+                    config.status_col_width = n;
+                }
+            }
+            ("sort", "mode") => config.sort_mode = parse_sort_mode(&unquote(value)),
+            ("cache", "ttl_secs") => {
+                if let Ok(n) = value.parse() {
+                    config.cache_ttl_secs = n;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    config
+}
+
+fn parse_sort_mode(value: &str) -> SortMode {
+    match value {
+        "playtime" => SortMode::Playtime,
+        "name" => SortMode::Name,
+        _ => SortMode::LastPlayed,
+    }
+}
+
+fn unquote(value: &str) -> String {
+    value.trim_matches('"').to_string()
+}
+
+fn parse_string_array(value: &str) -> Vec<String> {
+    value
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(unquote)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rofi_display_and_sort_settings() {
+        let config = parse_config(concat!(
+            "[rofi]\n",
+            "args = [\"-other-flag\", \"-case-insensitive\"]\n",
+            "prompt = \"play\"\n",
+            "monitor = \"0\"\n",
+            "\n",
+            "[display]\n",
+            "width = 100\n",
+            "hours_col_width = 10\n",
+            "\n",
+            "[sort]\n",
+            "mode = \"playtime\"\n",
+            "\n",
+            "[cache]\n",
+            "ttl_secs = 900\n",
+        ));
+
+        assert_eq!(config.rofi_args, vec!["-other-flag", "-case-insensitive"]);
+        assert_eq!(config.rofi_prompt, "play");
+        assert_eq!(config.rofi_monitor, Some(String::from("0")));
+        assert_eq!(config.display_width, 100);
+        assert_eq!(config.hours_col_width, 10);
+        assert_eq!(config.status_col_width, Config::default().status_col_width);
+        assert_eq!(config.sort_mode, SortMode::Playtime);
+        assert_eq!(config.cache_ttl_secs, 900);
+    }
+
+    #[test]
+    fn falls_back_to_defaults_for_missing_sections() {
+        let config = parse_config("");
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn glob_matches_wildcards_case_insensitively() {
+        assert!(matches_glob("Proton*", "proton experimental"));
+        assert!(matches_glob("*Runtime*", "Steam Linux Runtime 3.0"));
+        assert!(!matches_glob("Proton*", "Counter-Strike 2"));
+        assert!(matches_glob("Counter-Strike 2", "counter-strike 2"));
+    }
+}
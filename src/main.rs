@@ -1,4 +1,5 @@
-mod steam;
+mod config;
+mod sources;
 
 use std::env;
 use std::fs;
@@ -6,55 +7,146 @@ use std::fs::File;
 use std::path::PathBuf;
 use std::process;
 use std::process::{Command, Stdio};
-use steam::SteamGame;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-const LAUNCH_STR: &str = "launch";
+use config::{Config, SortMode};
+use sources::{Game, GameOrigin};
+
+/// Internal CLI flag used to re-exec ourselves as a detached background
+/// refresh; not a user-facing flag.
+const REFRESH_FLAG: &str = "--internal-refresh-cache";
 
-type Game = SteamGame;
-const DISPLAY_WIDTH: usize = 125;
-const HOURS_COL_WIDTH: usize = 8;
 const COL_SPACER: &str = "  ";
 
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Cache {
+    cached_at: u64,
+    games: Vec<Game>,
+}
+
 #[derive(Debug)]
 enum MenuChoice {
     Launch(Game),
     None,
 }
 
+/// Parsed user-facing CLI flags (distinct from the internal [`REFRESH_FLAG`]).
+#[derive(Debug, Default, PartialEq)]
+struct Cli {
+    refresh: bool,
+    no_cache: bool,
+    sources: Option<Vec<String>>,
+}
+
+fn parse_cli(args: &[String]) -> Cli {
+    let mut cli = Cli::default();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--refresh" => cli.refresh = true,
+            "--no-cache" => cli.no_cache = true,
+            "--source" => {
+                if let Some(value) = iter.next() {
+                    cli.sources = Some(value.split(',').map(str::trim).map(str::to_string).collect());
+                }
+            }
+            _ => {}
+        }
+    }
+    cli
+}
+
 fn cache_path() -> PathBuf {
     let home = env::var("HOME").unwrap_or_else(|_| String::from("~"));
     PathBuf::from(home).join(".launchablegames")
 }
 
-fn read_cache() -> Option<Vec<Game>> {
+fn read_cache() -> Option<Cache> {
     let content = fs::read_to_string(cache_path()).ok()?;
-    let games: Vec<Game> = serde_json::from_str(&content).ok()?;
-    if games.is_empty() { None } else { Some(games) }
+    let cache: Cache = serde_json::from_str(&content).ok()?;
+    if cache.games.is_empty() {
+        None
+    } else {
+        Some(cache)
+    }
 }
 
 fn write_cache(games: &[Game]) {
-    if let Ok(content) = serde_json::to_string(games) {
+    let cache = Cache {
+        cached_at: unix_now(),
+        games: games.to_vec(),
+    };
+    if let Ok(content) = serde_json::to_string(&cache) {
         let _ = fs::write(cache_path(), content);
     }
 }
 
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+/// Always scans every source, regardless of `--source`, so the cache and any
+/// background refresh stay complete; `--source` only filters what's displayed.
 fn refresh_cache_sync() -> Vec<Game> {
-    let games = steam::installed_games().unwrap_or_default();
+    let games = sources::scan_all();
     write_cache(&games);
     games
 }
 
-fn get_menu_selection(games: &[Game]) -> MenuChoice {
-    let title_width = DISPLAY_WIDTH.saturating_sub(HOURS_COL_WIDTH + COL_SPACER.len());
+/// Re-execs this binary with [`REFRESH_FLAG`] as a detached child so a stale
+/// cache can be rebuilt in the background without blocking the caller.
+fn spawn_background_refresh() {
+    if let Ok(exe) = env::current_exe() {
+        let _ = Command::new(exe)
+            .arg(REFRESH_FLAG)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+    }
+}
+
+/// Whether `game` matches an entry in the ignore list: a bare Steam appid, or
+/// a name glob (see [`config::matches_glob`]) checked against every backend.
+fn is_ignored(game: &Game, ignore_entries: &[String]) -> bool {
+    ignore_entries.iter().any(|entry| {
+        let matches_appid = match &game.origin {
+            GameOrigin::Steam(steam) => entry == &steam.appid,
+            _ => false,
+        };
+        matches_appid || config::matches_glob(entry, &game.name)
+    })
+}
+
+fn sort_games(games: &mut [Game], mode: SortMode) {
+    match mode {
+        SortMode::LastPlayed => games.sort_by_key(|game| std::cmp::Reverse(game.last_played)),
+        SortMode::Playtime => games.sort_by_key(|game| std::cmp::Reverse(game.playtime_minutes)),
+        SortMode::Name => games.sort_by_key(|game| game.name.clone()),
+    }
+}
+
+fn get_menu_selection(games: &[Game], config: &Config) -> MenuChoice {
+    let title_width = config.display_width.saturating_sub(
+        config.hours_col_width + config.status_col_width + COL_SPACER.len() * 2,
+    );
     let menu_rows: Vec<(String, Game)> = games
         .iter()
         .cloned()
         .map(|game| {
             let title = truncate_title(&game.name, title_width);
             let hours = format_hours(game.playtime_minutes);
+            let status = game.status_label();
             let row = format!(
-                "{:<title_width$}{COL_SPACER}{:>HOURS_COL_WIDTH$}",
-                title, hours
+                "{:<title_width$}{COL_SPACER}{:>hours_width$}{COL_SPACER}{:<status_width$}",
+                title,
+                hours,
+                status,
+                hours_width = config.hours_col_width,
+                status_width = config.status_col_width,
             );
             (row, game)
         })
@@ -70,18 +162,17 @@ fn get_menu_selection(games: &[Game]) -> MenuChoice {
     let _ = fs::write(&input_path, formatted.as_bytes());
     let stdin_file = File::open(&input_path).expect("failed to prepare rofi stdin");
 
-    let output = Command::new("rofi")
-        .arg("-monitor")
-        .arg("1")
-        .arg("-i")
-        .arg("-dmenu")
-        .arg("-sync")
+    let mut command = Command::new("rofi");
+    if let Some(monitor) = &config.rofi_monitor {
+        command.arg("-monitor").arg(monitor);
+    }
+    command
+        .args(&config.rofi_args)
         .arg("-p")
-        .arg(LAUNCH_STR)
+        .arg(&config.rofi_prompt)
         .stdin(Stdio::from(stdin_file))
-        .stdout(Stdio::piped())
-        .output()
-        .expect("failed to run rofi");
+        .stdout(Stdio::piped());
+    let output = command.output().expect("failed to run rofi");
     let _ = fs::remove_file(&input_path);
     let selected = String::from_utf8_lossy(&output.stdout)
         .lines()
@@ -116,9 +207,10 @@ fn truncate_title(title: &str, max_chars: usize) -> String {
     title.chars().take(max_chars - 3).collect::<String>() + "..."
 }
 
-fn launch_game(appid: &str) {
-    let _ = Command::new("steam")
-        .arg(format!("steam://rungameid/{appid}"))
+fn launch_game(game: &Game) {
+    let (command, args) = game.launch_command();
+    let _ = Command::new(command)
+        .args(args)
         .stdin(Stdio::null())
         .stdout(Stdio::null())
         .stderr(Stdio::null())
@@ -126,22 +218,47 @@ fn launch_game(appid: &str) {
 }
 
 fn main() {
-    let (games_list, used_cached_data) = match read_cache() {
-        Some(cached) => (cached, true),
-        None => (refresh_cache_sync(), false),
+    if env::args().nth(1).as_deref() == Some(REFRESH_FLAG) {
+        refresh_cache_sync();
+        return;
+    }
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let cli = parse_cli(&args);
+    let config = Config::load();
+
+    let mut games_list = if cli.no_cache {
+        sources::scan_selected(cli.sources.as_deref())
+    } else if cli.refresh {
+        refresh_cache_sync()
+    } else {
+        match read_cache() {
+            Some(cache) => {
+                if unix_now().saturating_sub(cache.cached_at) > config.cache_ttl_secs {
+                    spawn_background_refresh();
+                }
+                cache.games
+            }
+            None => refresh_cache_sync(),
+        }
     };
 
-    match get_menu_selection(&games_list) {
+    if let Some(names) = &cli.sources {
+        games_list.retain(|game| names.iter().any(|name| name.eq_ignore_ascii_case(game.source_name())));
+    }
+
+    let ignore_entries = config::load_ignore_list();
+    games_list.retain(|game| !is_ignored(game, &ignore_entries));
+
+    sort_games(&mut games_list, config.sort_mode);
+
+    match get_menu_selection(&games_list, &config) {
         MenuChoice::Launch(game) => {
             println!("{}", game.name);
-            launch_game(&game.appid);
+            launch_game(&game);
         }
         MenuChoice::None => {}
     }
-
-    if used_cached_data {
-        let _ = refresh_cache_sync();
-    }
 }
 
 #[cfg(test)]
@@ -160,4 +277,55 @@ mod tests {
         assert_eq!(truncate_title("abcdefg", 6), "abc...");
         assert_eq!(truncate_title("abcdef", 2), "..");
     }
+
+    #[test]
+    fn parses_refresh_no_cache_and_source_flags() {
+        let cli = parse_cli(&[
+            String::from("--refresh"),
+            String::from("--no-cache"),
+            String::from("--source"),
+            String::from("steam,lutris"),
+        ]);
+        assert!(cli.refresh);
+        assert!(cli.no_cache);
+        assert_eq!(
+            cli.sources,
+            Some(vec![String::from("steam"), String::from("lutris")])
+        );
+    }
+
+    #[test]
+    fn sorts_games_by_selected_mode() {
+        let mut games = vec![
+            Game {
+                name: String::from("B"),
+                last_played: 10,
+                playtime_minutes: 5,
+                origin: GameOrigin::Native(sources::NativeGame {
+                    name: String::from("B"),
+                    command: String::from("b"),
+                    args: Vec::new(),
+                }),
+            },
+            Game {
+                name: String::from("A"),
+                last_played: 20,
+                playtime_minutes: 1,
+                origin: GameOrigin::Native(sources::NativeGame {
+                    name: String::from("A"),
+                    command: String::from("a"),
+                    args: Vec::new(),
+                }),
+            },
+        ];
+
+        sort_games(&mut games, SortMode::Name);
+        assert_eq!(games[0].name, "A");
+
+        sort_games(&mut games, SortMode::LastPlayed);
+        assert_eq!(games[0].name, "A");
+
+        sort_games(&mut games, SortMode::Playtime);
+        assert_eq!(games[0].name, "B");
+    }
 }
@@ -0,0 +1,132 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A game registered with Heroic (Epic/GOG/Amazon via Heroic's unified library).
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct HeroicGame {
+    pub title: String,
+    pub app_name: String,
+}
+
+/// [`super::GameSource`] backed by Heroic's `gamesConfig/*.json` files.
+pub struct HeroicSource;
+
+impl HeroicSource {
+    pub const NAME: &'static str = "heroic";
+}
+
+pub fn installed_games() -> io::Result<Vec<HeroicGame>> {
+    let home =
+        env::var("HOME").map_err(|_| io::Error::new(io::ErrorKind::NotFound, "HOME is not set"))?;
+    installed_games_from_root(Path::new(&home).join(".config/heroic").as_path())
+}
+
+pub fn installed_games_from_root(heroic_root: &Path) -> io::Result<Vec<HeroicGame>> {
+    let games_config_dir = heroic_root.join("gamesConfig");
+    let entries = match fs::read_dir(&games_config_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut games = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+
+        let app_name = match path.file_stem().and_then(|s| s.to_str()) {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+
+        if let Some(title) = parse_title(&content) {
+            games.push(HeroicGame { title, app_name });
+        }
+    }
+
+    games.sort_by(|a, b| a.title.cmp(&b.title));
+    Ok(games)
+}
+
+/// Each `gamesConfig/<app_name>.json` is a settings object rather than a game
+/// manifest, so the title isn't guaranteed to be present; callers skip entries
+/// where it's missing rather than treating that as a parse error.
+fn parse_title(content: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(content).ok()?;
+    value
+        .get("title")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_file(path: &Path, content: &str) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dirs");
+        }
+        fs::write(path, content).expect("failed to write file");
+    }
+
+    #[test]
+    fn parses_title_from_settings_json() {
+        assert_eq!(
+            parse_title(r#"{"title": "Spelunky", "other": 1}"#),
+            Some("Spelunky".to_string())
+        );
+    }
+
+    #[test]
+    fn returns_none_when_title_is_missing() {
+        assert_eq!(parse_title(r#"{"other": 1}"#), None);
+    }
+
+    #[test]
+    fn returns_none_for_malformed_json() {
+        assert_eq!(parse_title("not json"), None);
+    }
+
+    #[test]
+    fn returns_empty_vec_for_missing_games_config_dir() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        let games = installed_games_from_root(tmp.path()).expect("failed to load games");
+        assert!(games.is_empty());
+    }
+
+    #[test]
+    fn skips_entries_missing_title_and_non_json_files() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        write_file(
+            &tmp.path().join("gamesConfig").join("notes.txt"),
+            r#"{"title": "Ignored"}"#,
+        );
+        write_file(
+            &tmp.path().join("gamesConfig").join("untitled.json"),
+            r#"{"other": 1}"#,
+        );
+        write_file(
+            &tmp.path().join("gamesConfig").join("spelunky.json"),
+            r#"{"title": "Spelunky"}"#,
+        );
+
+        let games = installed_games_from_root(tmp.path()).expect("failed to load games");
+        assert_eq!(
+            games,
+            vec![HeroicGame {
+                title: "Spelunky".to_string(),
+                app_name: "spelunky".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A game installed through itch's `butler`-managed cave database.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ItchGame {
+    pub title: String,
+    pub cave_id: String,
+}
+
+/// [`super::GameSource`] backed by butler's installed-games (cave) database.
+pub struct ItchSource;
+
+impl ItchSource {
+    pub const NAME: &'static str = "itch";
+}
+
+pub fn installed_games() -> io::Result<Vec<ItchGame>> {
+    let home =
+        env::var("HOME").map_err(|_| io::Error::new(io::ErrorKind::NotFound, "HOME is not set"))?;
+    installed_games_from_root(Path::new(&home).join(".config/itch").as_path())
+}
+
+pub fn installed_games_from_root(itch_root: &Path) -> io::Result<Vec<ItchGame>> {
+    let db_path = itch_root.join("db").join("butler.db.json");
+    let content = match fs::read_to_string(&db_path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut games = parse_caves(&content);
+    games.sort_by(|a, b| a.title.cmp(&b.title));
+    Ok(games)
+}
+
+/// butler exposes its cave database over a local API; `butler.db.json` here
+/// stands in for whatever export/cache shape callers feed us, keyed by cave id.
+fn parse_caves(content: &str) -> Vec<ItchGame> {
+    let value: serde_json::Value = match serde_json::from_str(content) {
+        Ok(value) => value,
+        Err(_) => return Vec::new(),
+    };
+
+    let caves = match value.get("caves").and_then(|v| v.as_object()) {
+        Some(caves) => caves,
+        None => return Vec::new(),
+    };
+
+    caves
+        .iter()
+        .filter_map(|(cave_id, cave)| {
+            let title = cave.get("game")?.get("title")?.as_str()?.to_string();
+            Some(ItchGame {
+                title,
+                cave_id: cave_id.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_file(path: &Path, content: &str) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dirs");
+        }
+        fs::write(path, content).expect("failed to write file");
+    }
+
+    #[test]
+    fn parses_caves_keyed_by_cave_id() {
+        let mut games = parse_caves(
+            r#"{"caves": {"42": {"game": {"title": "Celeste"}}}}"#,
+        );
+        games.sort_by(|a, b| a.title.cmp(&b.title));
+        assert_eq!(
+            games,
+            vec![ItchGame {
+                title: "Celeste".to_string(),
+                cave_id: "42".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn skips_caves_missing_a_title() {
+        let games = parse_caves(r#"{"caves": {"42": {"game": {}}}}"#);
+        assert!(games.is_empty());
+    }
+
+    #[test]
+    fn returns_empty_vec_for_malformed_json() {
+        assert!(parse_caves("not json").is_empty());
+    }
+
+    #[test]
+    fn returns_empty_vec_when_caves_key_is_missing() {
+        assert!(parse_caves(r#"{"other": 1}"#).is_empty());
+    }
+
+    #[test]
+    fn returns_empty_vec_for_missing_db_file() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        let games = installed_games_from_root(tmp.path()).expect("failed to load games");
+        assert!(games.is_empty());
+    }
+
+    #[test]
+    fn reads_caves_from_root_db_file() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        write_file(
+            &tmp.path().join("db").join("butler.db.json"),
+            r#"{"caves": {"7": {"game": {"title": "Towerfall"}}}}"#,
+        );
+
+        let games = installed_games_from_root(tmp.path()).expect("failed to load games");
+        assert_eq!(
+            games,
+            vec![ItchGame {
+                title: "Towerfall".to_string(),
+                cave_id: "7".to_string(),
+            }]
+        );
+    }
+}
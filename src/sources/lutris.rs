@@ -0,0 +1,122 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A game registered with Lutris, parsed from its per-game YAML config.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LutrisGame {
+    pub name: String,
+    pub slug: String,
+}
+
+/// [`super::GameSource`] backed by Lutris's per-game YAML configs.
+pub struct LutrisSource;
+
+impl LutrisSource {
+    pub const NAME: &'static str = "lutris";
+}
+
+pub fn installed_games() -> io::Result<Vec<LutrisGame>> {
+    let home =
+        env::var("HOME").map_err(|_| io::Error::new(io::ErrorKind::NotFound, "HOME is not set"))?;
+    installed_games_from_root(Path::new(&home).join(".local/share/lutris").as_path())
+}
+
+pub fn installed_games_from_root(lutris_root: &Path) -> io::Result<Vec<LutrisGame>> {
+    let games_dir = lutris_root.join("games");
+    let entries = match fs::read_dir(&games_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut games = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("yml") {
+            continue;
+        }
+
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+
+        if let Some(game) = parse_game_config(&content, &path) {
+            games.push(game);
+        }
+    }
+
+    games.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(games)
+}
+
+/// Lutris's per-game configs are flat `key: value` YAML; we only need the
+/// `name` scalar (the slug is the file stem), so a line scan is enough without
+/// pulling in a YAML crate.
+fn parse_game_config(content: &str, path: &Path) -> Option<LutrisGame> {
+    let mut name = None;
+    for line in content.lines() {
+        let (key, value) = line.split_once(':')?;
+        if key.trim() == "name" {
+            name = Some(value.trim().trim_matches('"').to_string());
+        }
+    }
+
+    let slug = path.file_stem().and_then(|s| s.to_str())?.to_string();
+    Some(LutrisGame { name: name?, slug })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_file(path: &Path, content: &str) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dirs");
+        }
+        fs::write(path, content).expect("failed to write file");
+    }
+
+    #[test]
+    fn parses_name_and_slug_from_game_config() {
+        let path = Path::new("doom.yml");
+        let game = parse_game_config("name: Doom\ndirector: id Software\n", path)
+            .expect("expected a parsed game");
+        assert_eq!(game.name, "Doom");
+        assert_eq!(game.slug, "doom");
+    }
+
+    #[test]
+    fn returns_none_when_name_key_is_missing() {
+        let path = Path::new("doom.yml");
+        assert_eq!(parse_game_config("runner: linux\n", path), None);
+    }
+
+    #[test]
+    fn returns_empty_vec_for_missing_games_dir() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        let games = installed_games_from_root(tmp.path()).expect("failed to load games");
+        assert!(games.is_empty());
+    }
+
+    #[test]
+    fn skips_non_yml_files_in_games_dir() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        write_file(&tmp.path().join("games").join("notes.txt"), "name: Ignored\n");
+        write_file(
+            &tmp.path().join("games").join("doom.yml"),
+            "name: Doom\n",
+        );
+
+        let games = installed_games_from_root(tmp.path()).expect("failed to load games");
+        assert_eq!(
+            games,
+            vec![LutrisGame {
+                name: "Doom".to_string(),
+                slug: "doom".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,258 @@
+pub mod heroic;
+pub mod itch;
+pub mod lutris;
+pub mod native;
+pub mod steam;
+
+use std::io;
+
+pub use heroic::HeroicGame;
+pub use itch::ItchGame;
+pub use lutris::LutrisGame;
+pub use native::NativeGame;
+pub use steam::SteamGame;
+
+/// A launchable entry gathered from one of the `GameSource` implementations below.
+///
+/// Fields common to every backend (`name`, `last_played`, `playtime_minutes`) live
+/// directly on `Game`; anything backend-specific (appid, slug, on-disk paths, ...)
+/// lives on the `origin` variant's own payload type.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Game {
+    pub name: String,
+    #[serde(default)]
+    pub last_played: u64,
+    #[serde(default)]
+    pub playtime_minutes: u64,
+    pub origin: GameOrigin,
+}
+
+/// Which backend a [`Game`] came from, carrying that backend's own representation.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "source")]
+pub enum GameOrigin {
+    Steam(SteamGame),
+    Lutris(LutrisGame),
+    Heroic(HeroicGame),
+    Itch(ItchGame),
+    Native(NativeGame),
+}
+
+impl Game {
+    /// The `--source` name this game would be matched by, e.g. `"steam"`.
+    pub fn source_name(&self) -> &'static str {
+        match &self.origin {
+            GameOrigin::Steam(_) => steam::SteamSource::NAME,
+            GameOrigin::Lutris(_) => lutris::LutrisSource::NAME,
+            GameOrigin::Heroic(_) => heroic::HeroicSource::NAME,
+            GameOrigin::Itch(_) => itch::ItchSource::NAME,
+            GameOrigin::Native(_) => native::NativeSource::NAME,
+        }
+    }
+
+    /// A short install-state summary for display (e.g. "installed 12.4GB");
+    /// empty for backends that don't track install state.
+    pub fn status_label(&self) -> String {
+        match &self.origin {
+            GameOrigin::Steam(game) => game.status_label(),
+            GameOrigin::Lutris(_)
+            | GameOrigin::Heroic(_)
+            | GameOrigin::Itch(_)
+            | GameOrigin::Native(_) => String::new(),
+        }
+    }
+
+    /// The command and arguments used to launch this game, independent of backend.
+    pub fn launch_command(&self) -> (String, Vec<String>) {
+        match &self.origin {
+            GameOrigin::Steam(game) => game.launch_command(),
+            GameOrigin::Lutris(game) => (
+                String::from("xdg-open"),
+                vec![format!("lutris:rungame/{}", game.slug)],
+            ),
+            GameOrigin::Heroic(game) => (
+                String::from("xdg-open"),
+                vec![format!("heroic://launch?appName={}", game.app_name)],
+            ),
+            GameOrigin::Itch(game) => (
+                String::from("xdg-open"),
+                vec![format!("itch://caves/{}/launch", game.cave_id)],
+            ),
+            GameOrigin::Native(game) => (game.command.clone(), game.args.clone()),
+        }
+    }
+}
+
+impl From<SteamGame> for Game {
+    fn from(game: SteamGame) -> Self {
+        Game {
+            name: game.name.clone(),
+            last_played: game.last_played,
+            playtime_minutes: game.playtime_minutes,
+            origin: GameOrigin::Steam(game),
+        }
+    }
+}
+
+impl From<LutrisGame> for Game {
+    fn from(game: LutrisGame) -> Self {
+        Game {
+            name: game.name.clone(),
+            last_played: 0,
+            playtime_minutes: 0,
+            origin: GameOrigin::Lutris(game),
+        }
+    }
+}
+
+impl From<HeroicGame> for Game {
+    fn from(game: HeroicGame) -> Self {
+        Game {
+            name: game.title.clone(),
+            last_played: 0,
+            playtime_minutes: 0,
+            origin: GameOrigin::Heroic(game),
+        }
+    }
+}
+
+impl From<ItchGame> for Game {
+    fn from(game: ItchGame) -> Self {
+        Game {
+            name: game.title.clone(),
+            last_played: 0,
+            playtime_minutes: 0,
+            origin: GameOrigin::Itch(game),
+        }
+    }
+}
+
+impl From<NativeGame> for Game {
+    fn from(game: NativeGame) -> Self {
+        Game {
+            name: game.name.clone(),
+            last_played: 0,
+            playtime_minutes: 0,
+            origin: GameOrigin::Native(game),
+        }
+    }
+}
+
+/// Parses one `key|command|arg1;arg2;...` line (the trailing `|args` segment
+/// is optional), shared by the native-games registry and the Steam
+/// launch-override file, which both use this format. Blank lines and `#`
+/// comments yield `None`.
+pub(crate) fn parse_pipe_line(line: &str) -> Option<(String, String, Vec<String>)> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let mut parts = line.splitn(3, '|');
+    let key = parts.next()?.trim().to_string();
+    let command = parts.next()?.trim().to_string();
+    let args = parts
+        .next()
+        .map(|raw| {
+            raw.split(';')
+                .map(str::trim)
+                .filter(|arg| !arg.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some((key, command, args))
+}
+
+/// A backend that can scan the local machine for installed/registered games.
+///
+/// Implemented once per launcher (Steam, Lutris, Heroic/GOG, itch, plain native
+/// executables) so `main` can treat them uniformly: scan every enabled source,
+/// concatenate the results, and hand the combined list to the menu.
+pub trait GameSource {
+    /// The name matched against the `--source` CLI flag, e.g. `"steam"`.
+    fn name(&self) -> &'static str;
+    fn scan(&self) -> io::Result<Vec<Game>>;
+}
+
+impl GameSource for steam::SteamSource {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn scan(&self) -> io::Result<Vec<Game>> {
+        Ok(steam::installed_games()?.into_iter().map(Game::from).collect())
+    }
+}
+
+impl GameSource for lutris::LutrisSource {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn scan(&self) -> io::Result<Vec<Game>> {
+        Ok(lutris::installed_games()?.into_iter().map(Game::from).collect())
+    }
+}
+
+impl GameSource for heroic::HeroicSource {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn scan(&self) -> io::Result<Vec<Game>> {
+        Ok(heroic::installed_games()?.into_iter().map(Game::from).collect())
+    }
+}
+
+impl GameSource for itch::ItchSource {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn scan(&self) -> io::Result<Vec<Game>> {
+        Ok(itch::installed_games()?.into_iter().map(Game::from).collect())
+    }
+}
+
+impl GameSource for native::NativeSource {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn scan(&self) -> io::Result<Vec<Game>> {
+        Ok(native::registered_games()?.into_iter().map(Game::from).collect())
+    }
+}
+
+/// All built-in sources, in the order their games should appear when merged.
+pub fn all_sources() -> Vec<Box<dyn GameSource>> {
+    vec![
+        Box::new(steam::SteamSource),
+        Box::new(lutris::LutrisSource),
+        Box::new(heroic::HeroicSource),
+        Box::new(itch::ItchSource),
+        Box::new(native::NativeSource),
+    ]
+}
+
+/// Scans every source in [`all_sources`], ignoring individual backends that fail
+/// (e.g. a launcher that isn't installed) rather than aborting the whole scan.
+pub fn scan_all() -> Vec<Game> {
+    scan_selected(None)
+}
+
+/// Scans the sources named in `names` (matched case-insensitively against
+/// [`GameSource::name`]), or every source when `names` is `None`.
+pub fn scan_selected(names: Option<&[String]>) -> Vec<Game> {
+    all_sources()
+        .into_iter()
+        .filter(|source| match names {
+            Some(names) => names.iter().any(|name| name.eq_ignore_ascii_case(source.name())),
+            None => true,
+        })
+        .filter_map(|source| source.scan().ok())
+        .flatten()
+        .collect()
+}
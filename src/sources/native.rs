@@ -0,0 +1,118 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A plain native executable the user has registered manually (not owned by
+/// any launcher), e.g. a standalone game binary or a custom wrapper script.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct NativeGame {
+    pub name: String,
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+/// [`super::GameSource`] backed by the user's native-executable registry file.
+pub struct NativeSource;
+
+impl NativeSource {
+    pub const NAME: &'static str = "native";
+}
+
+pub fn registered_games() -> io::Result<Vec<NativeGame>> {
+    let home =
+        env::var("HOME").map_err(|_| io::Error::new(io::ErrorKind::NotFound, "HOME is not set"))?;
+    registered_games_from_path(
+        Path::new(&home)
+            .join(".config/rofi-steam/native-games.txt")
+            .as_path(),
+    )
+}
+
+/// One game per line: `Name|command|arg1;arg2;...` (args and the trailing
+/// `|args` segment are optional).
+pub fn registered_games_from_path(path: &Path) -> io::Result<Vec<NativeGame>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let games = content
+        .lines()
+        .filter_map(parse_line)
+        .collect::<Vec<_>>();
+    Ok(games)
+}
+
+fn parse_line(line: &str) -> Option<NativeGame> {
+    let (name, command, args) = super::parse_pipe_line(line)?;
+    Some(NativeGame {
+        name,
+        command,
+        args,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_file(path: &Path, content: &str) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dirs");
+        }
+        fs::write(path, content).expect("failed to write file");
+    }
+
+    #[test]
+    fn parses_name_command_and_args() {
+        let game = parse_line("Doom|doomrunner|-iwad;doom.wad").expect("expected a parsed game");
+        assert_eq!(game.name, "Doom");
+        assert_eq!(game.command, "doomrunner");
+        assert_eq!(game.args, vec!["-iwad", "doom.wad"]);
+    }
+
+    #[test]
+    fn defaults_args_when_segment_is_missing() {
+        let game = parse_line("Doom|doomrunner").expect("expected a parsed game");
+        assert!(game.args.is_empty());
+    }
+
+    #[test]
+    fn skips_blank_and_comment_lines() {
+        assert_eq!(parse_line(""), None);
+        assert_eq!(parse_line("# Doom|doomrunner"), None);
+    }
+
+    #[test]
+    fn returns_none_when_command_is_missing() {
+        assert_eq!(parse_line("Doom"), None);
+    }
+
+    #[test]
+    fn returns_empty_vec_for_missing_registry_file() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        let games = registered_games_from_path(&tmp.path().join("native-games.txt"))
+            .expect("failed to load games");
+        assert!(games.is_empty());
+    }
+
+    #[test]
+    fn reads_registered_games_from_file() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        let path = tmp.path().join("native-games.txt");
+        write_file(&path, "# comment\nDoom|doomrunner|-iwad;doom.wad\n");
+
+        let games = registered_games_from_path(&path).expect("failed to load games");
+        assert_eq!(
+            games,
+            vec![NativeGame {
+                name: "Doom".to_string(),
+                command: "doomrunner".to_string(),
+                args: vec!["-iwad".to_string(), "doom.wad".to_string()],
+            }]
+        );
+    }
+}
@@ -0,0 +1,847 @@
+mod shortcuts;
+mod vdf;
+
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use shortcuts::BinVdfValue;
+use vdf::VdfValue;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SteamGame {
+    pub name: String,
+    pub appid: String,
+    #[serde(default)]
+    pub last_played: u64,
+    #[serde(default)]
+    pub playtime_minutes: u64,
+    #[serde(default)]
+    pub install_state: InstallState,
+    #[serde(default)]
+    pub size_on_disk_bytes: u64,
+    /// The user's Steam-configured launch options (the `LaunchOptions` key in
+    /// `localconfig.vdf`), appended verbatim via `steam://run/<appid>//<args>/`.
+    #[serde(default)]
+    pub launch_options: String,
+}
+
+/// Where a game stands relative to what's installed on disk, derived from an
+/// appmanifest's `StateFlags`/`Bytes*` fields.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum InstallState {
+    #[default]
+    Installed,
+    UpdatePending,
+    Downloading,
+}
+
+impl InstallState {
+    fn label(self) -> &'static str {
+        match self {
+            InstallState::Installed => "installed",
+            InstallState::UpdatePending => "update",
+            InstallState::Downloading => "downloading",
+        }
+    }
+}
+
+impl SteamGame {
+    pub fn launch_command(&self) -> (String, Vec<String>) {
+        if let Some(overridden) = launch_override(&self.appid) {
+            return overridden;
+        }
+
+        let launch_uri = if self.launch_options.is_empty() {
+            format!("steam://rungameid/{}", self.appid)
+        } else {
+            format!("steam://run/{}//{}/", self.appid, self.launch_options)
+        };
+        (String::from("steam"), vec![launch_uri])
+    }
+
+    /// A short human-readable summary of install state and size, e.g.
+    /// `"installed 12.4GB"` or `"downloading 4.5GB"`; the size is always the
+    /// appmanifest's `SizeOnDisk`, not download progress.
+    pub fn status_label(&self) -> String {
+        format!(
+            "{} {}",
+            self.install_state.label(),
+            format_bytes(self.size_on_disk_bytes)
+        )
+    }
+}
+
+/// `StateFlags` bit 2 (`0x4`) marks an app as fully installed; when no
+/// download is in progress, its absence is reported as an update pending.
+const STATE_FLAG_FULLY_INSTALLED: u64 = 0x4;
+
+fn install_state(state_flags: u64, bytes_downloaded: u64, bytes_to_download: u64) -> InstallState {
+    if bytes_to_download > 0 && bytes_downloaded < bytes_to_download {
+        InstallState::Downloading
+    } else if state_flags & STATE_FLAG_FULLY_INSTALLED == 0 {
+        InstallState::UpdatePending
+    } else {
+        InstallState::Installed
+    }
+}
+
+/// One override per line: `appid|command|arg1;arg2;...` (args are optional),
+/// read from `~/.config/rofi-steam/launch-overrides.conf`. Lets a user run a
+/// game through a specific Proton prefix or a wrapper instead of plain Steam.
+fn launch_override(appid: &str) -> Option<(String, Vec<String>)> {
+    let home = env::var("HOME").ok()?;
+    let path = Path::new(&home).join(".config/rofi-steam/launch-overrides.conf");
+    let content = fs::read_to_string(path).ok()?;
+
+    content.lines().find_map(|line| {
+        let (key, command, args) = super::parse_pipe_line(line)?;
+        if key != appid {
+            return None;
+        }
+        Some((command, args))
+    })
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{value:.0}{}", UNITS[unit])
+    } else {
+        format!("{value:.1}{}", UNITS[unit])
+    }
+}
+
+/// [`super::GameSource`] backed by the local Steam installation.
+pub struct SteamSource;
+
+impl SteamSource {
+    pub const NAME: &'static str = "steam";
+}
+
+pub fn installed_games() -> io::Result<Vec<SteamGame>> {
+    let home =
+        env::var("HOME").map_err(|_| io::Error::new(io::ErrorKind::NotFound, "HOME is not set"))?;
+    installed_games_from_root(Path::new(&home).join(".steam").as_path())
+}
+
+pub fn installed_games_from_root(steam_root: &Path) -> io::Result<Vec<SteamGame>> {
+    let primary_steamapps = steam_root.join("steam").join("steamapps");
+    let mut library_paths = parse_library_paths(&primary_steamapps.join("libraryfolders.vdf"))?;
+    let local_configs = parse_local_configs(steam_root);
+
+    if library_paths.is_empty() {
+        library_paths.push(steam_root.join("steam"));
+    }
+
+    let mut seen = HashSet::new();
+    let mut games = Vec::new();
+
+    for library in library_paths {
+        let steamapps = library.join("steamapps");
+        if !steamapps.is_dir() {
+            continue;
+        }
+
+        let entries = match fs::read_dir(&steamapps) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let filename = match path.file_name().and_then(|n| n.to_str()) {
+                Some(name) => name,
+                None => continue,
+            };
+
+            if !filename.starts_with("appmanifest_") || !filename.ends_with(".acf") {
+                continue;
+            }
+
+            let content = match fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(_) => continue,
+            };
+
+            if let Some(game) = parse_appmanifest(&content) {
+                if is_game_entry(&game) && seen.insert(game.appid.clone()) {
+                    let local_config = local_configs.get(&game.appid).cloned().unwrap_or_default();
+                    games.push(SteamGame {
+                        playtime_minutes: local_config.playtime_minutes,
+                        launch_options: local_config.launch_options,
+                        ..game
+                    });
+                }
+            }
+        }
+    }
+
+    for shortcut in parse_shortcuts(steam_root) {
+        if seen.insert(shortcut.appid.clone()) {
+            let local_config = local_configs.get(&shortcut.appid).cloned().unwrap_or_default();
+            games.push(SteamGame {
+                playtime_minutes: local_config.playtime_minutes,
+                launch_options: local_config.launch_options,
+                ..shortcut
+            });
+        }
+    }
+
+    games.sort_by(|a, b| {
+        b.last_played
+            .cmp(&a.last_played)
+            .then_with(|| a.appid.cmp(&b.appid))
+    });
+    Ok(games)
+}
+
+/// Reads every user's `config/shortcuts.vdf` — Steam's binary-VDF registry of
+/// "non-Steam game" shortcuts (emulators, external launchers, ...) — and
+/// turns each entry into a [`SteamGame`] launched the same way as any other
+/// installed game, via `steam://rungameid/<appid>`.
+fn parse_shortcuts(steam_root: &Path) -> Vec<SteamGame> {
+    let userdata = steam_root.join("steam").join("userdata");
+    let entries = match fs::read_dir(userdata) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut games = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path().join("config").join("shortcuts.vdf");
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(_) => continue,
+        };
+
+        let root = shortcuts::parse(&bytes);
+        let Some(shortcut_entries) = root.get("shortcuts").and_then(BinVdfValue::as_map) else {
+            continue;
+        };
+
+        games.extend(shortcut_entries.values().filter_map(shortcut_to_game));
+    }
+
+    games
+}
+
+fn shortcut_to_game(shortcut: &BinVdfValue) -> Option<SteamGame> {
+    let fields = shortcut.as_map()?;
+    let name = fields.get("AppName")?.as_str()?.to_string();
+    // `Exe` is required for a shortcut to actually launch anything; a shortcut
+    // that's missing it isn't one we can usefully show.
+    fields.get("Exe")?.as_str()?;
+    let appid = (fields.get("appid")?.as_int()? as u32).to_string();
+
+    Some(SteamGame {
+        name,
+        appid,
+        ..Default::default()
+    })
+}
+
+fn parse_library_paths(libraryfolders_file: &Path) -> io::Result<Vec<PathBuf>> {
+    let content = match fs::read_to_string(libraryfolders_file) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let root = vdf::parse(&content);
+    let paths = root
+        .query("libraryfolders.*.path")
+        .into_iter()
+        .filter_map(VdfValue::as_str)
+        .map(|path| PathBuf::from(path.replace("\\\\", "\\")))
+        .collect();
+
+    Ok(paths)
+}
+
+fn parse_appmanifest(content: &str) -> Option<SteamGame> {
+    let root = vdf::parse(content);
+
+    let name = first_str(&root, "AppState.name")?.to_string();
+    let appid = first_str(&root, "AppState.appid")?.to_string();
+    let last_played = parse_u64_field(&root, "AppState.LastPlayed");
+    let state_flags = parse_u64_field(&root, "AppState.StateFlags");
+    let size_on_disk_bytes = parse_u64_field(&root, "AppState.SizeOnDisk");
+    let bytes_downloaded = parse_u64_field(&root, "AppState.BytesDownloaded");
+    let bytes_to_download = parse_u64_field(&root, "AppState.BytesToDownload");
+
+    Some(SteamGame {
+        name,
+        appid,
+        last_played,
+        playtime_minutes: 0,
+        install_state: install_state(state_flags, bytes_downloaded, bytes_to_download),
+        size_on_disk_bytes,
+        launch_options: String::new(),
+    })
+}
+
+fn parse_u64_field(value: &VdfValue, path: &str) -> u64 {
+    first_str(value, path)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0)
+}
+
+fn first_str<'a>(value: &'a VdfValue, path: &str) -> Option<&'a str> {
+    value.query(path).into_iter().next()?.as_str()
+}
+
+/// Per-app data from a user's `localconfig.vdf`, alongside the appmanifest.
+#[derive(Clone, Debug, Default)]
+struct LocalConfig {
+    playtime_minutes: u64,
+    launch_options: String,
+}
+
+fn parse_local_configs(steam_root: &Path) -> HashMap<String, LocalConfig> {
+    let mut result: HashMap<String, LocalConfig> = HashMap::new();
+    let userdata = steam_root.join("steam").join("userdata");
+    let entries = match fs::read_dir(userdata) {
+        Ok(entries) => entries,
+        Err(_) => return result,
+    };
+
+    for entry in entries.flatten() {
+        let config = entry.path().join("config").join("localconfig.vdf");
+        let content = match fs::read_to_string(config) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+
+        for (appid, parsed) in parse_localconfig_apps(&content) {
+            let current = result.entry(appid).or_default();
+            if parsed.playtime_minutes > current.playtime_minutes {
+                current.playtime_minutes = parsed.playtime_minutes;
+            }
+            if current.launch_options.is_empty() {
+                current.launch_options = parsed.launch_options;
+            }
+        }
+    }
+
+    result
+}
+
+fn parse_localconfig_apps(content: &str) -> HashMap<String, LocalConfig> {
+    let root = vdf::parse(content);
+    let Some(apps) = root
+        .query("UserLocalConfigStore.Software.Valve.Steam.apps")
+        .into_iter()
+        .next()
+        .and_then(VdfValue::as_map)
+    else {
+        return HashMap::new();
+    };
+
+    apps.iter()
+        .filter_map(|(appid, app)| {
+            let app = app.as_map()?;
+            let playtime_minutes = app
+                .get("Playtime")
+                .and_then(VdfValue::as_str)
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0);
+            let launch_options = app
+                .get("LaunchOptions")
+                .and_then(VdfValue::as_str)
+                .unwrap_or("")
+                .to_string();
+            Some((
+                appid.clone(),
+                LocalConfig {
+                    playtime_minutes,
+                    launch_options,
+                },
+            ))
+        })
+        .collect()
+}
+
+fn is_game_entry(game: &SteamGame) -> bool {
+    let name = game.name.to_ascii_lowercase();
+    !name.contains("proton")
+        && !name.contains("steam linux runtime")
+        && !name.contains("steamworks common redistributables")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write_file(path: &Path, content: &str) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("failed to create parent dirs");
+        }
+        fs::write(path, content).expect("failed to write file");
+    }
+
+    #[test]
+    fn finds_games_across_all_libraries() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        let steam_root = tmp.path().join(".steam");
+
+        let primary = steam_root.join("steam");
+        let extra = tmp.path().join("mnt").join("games");
+
+        write_file(
+            &primary.join("steamapps").join("libraryfolders.vdf"),
+            &format!(
+                concat!(
+                    "\"libraryfolders\"\n",
+                    "{{\n",
+                    "  \"0\"\n",
+                    "  {{\n",
+                    "    \"path\"\t\"{}\"\n",
+                    "  }}\n",
+                    "  \"1\"\n",
+                    "  {{\n",
+                    "    \"path\"\t\"{}\"\n",
+                    "  }}\n",
+                    "}}\n"
+                ),
+                primary.display(),
+                extra.display()
+            ),
+        );
+
+        write_file(
+            &primary.join("steamapps").join("appmanifest_10.acf"),
+            concat!(
+                "\"AppState\"\n",
+                "{\n",
+                "  \"appid\"\t\"10\"\n",
+                "  \"name\"\t\"Counter-Strike\"\n",
+                "  \"StateFlags\"\t\"4\"\n",
+                "}\n"
+            ),
+        );
+
+        write_file(
+            &extra.join("steamapps").join("appmanifest_20.acf"),
+            concat!(
+                "\"AppState\"\n",
+                "{\n",
+                "  \"appid\"\t\"20\"\n",
+                "  \"name\"\t\"Team Fortress Classic\"\n",
+                "  \"StateFlags\"\t\"4\"\n",
+                "}\n"
+            ),
+        );
+
+        let games = installed_games_from_root(&steam_root).expect("failed to load games");
+
+        assert_eq!(
+            games,
+            vec![
+                SteamGame {
+                    name: "Counter-Strike".to_string(),
+                    appid: "10".to_string(),
+                    last_played: 0,
+                    playtime_minutes: 0,
+                    install_state: InstallState::Installed,
+                    size_on_disk_bytes: 0,
+                    launch_options: String::new(),
+                },
+                SteamGame {
+                    name: "Team Fortress Classic".to_string(),
+                    appid: "20".to_string(),
+                    last_played: 0,
+                    playtime_minutes: 0,
+                    install_state: InstallState::Installed,
+                    size_on_disk_bytes: 0,
+                    launch_options: String::new(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn falls_back_to_default_library_when_no_libraryfolders_file() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        let steam_root = tmp.path().join(".steam");
+
+        let primary_manifest = steam_root
+            .join("steam")
+            .join("steamapps")
+            .join("appmanifest_730.acf");
+
+        write_file(
+            &primary_manifest,
+            concat!(
+                "\"AppState\"\n",
+                "{\n",
+                "  \"appid\"\t\"730\"\n",
+                "  \"name\"\t\"Counter-Strike 2\"\n",
+                "  \"StateFlags\"\t\"4\"\n",
+                "}\n"
+            ),
+        );
+
+        let games = installed_games_from_root(&steam_root).expect("failed to load games");
+
+        assert_eq!(
+            games,
+            vec![SteamGame {
+                name: "Counter-Strike 2".to_string(),
+                appid: "730".to_string(),
+                last_played: 0,
+                playtime_minutes: 0,
+                install_state: InstallState::Installed,
+                size_on_disk_bytes: 0,
+                launch_options: String::new(),
+            }]
+        );
+    }
+
+    #[test]
+    #[ignore = "depends on local ~/.steam contents"]
+    fn reads_installed_games_from_home_dir() {
+        let games = installed_games().expect("failed to read installed games from ~/.steam");
+        assert!(
+            games.len() > 10,
+            "expected more than 10 installed steam games, found {}",
+            games.len()
+        );
+        for game in games {
+            assert!(
+                is_game_entry(&game),
+                "found non-game entry in results: {} ({})",
+                game.name,
+                game.appid
+            );
+        }
+    }
+
+    #[test]
+    fn filters_known_non_game_entries() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        let steam_root = tmp.path().join(".steam");
+        let steamapps = steam_root.join("steam").join("steamapps");
+
+        write_file(
+            &steamapps.join("appmanifest_1493710.acf"),
+            concat!(
+                "\"AppState\"\n",
+                "{\n",
+                "  \"appid\"\t\"1493710\"\n",
+                "  \"name\"\t\"Proton Experimental\"\n",
+                "  \"StateFlags\"\t\"4\"\n",
+                "}\n"
+            ),
+        );
+
+        write_file(
+            &steamapps.join("appmanifest_1628350.acf"),
+            concat!(
+                "\"AppState\"\n",
+                "{\n",
+                "  \"appid\"\t\"1628350\"\n",
+                "  \"name\"\t\"Steam Linux Runtime 3.0 (sniper)\"\n",
+                "  \"StateFlags\"\t\"4\"\n",
+                "}\n"
+            ),
+        );
+
+        write_file(
+            &steamapps.join("appmanifest_1030300.acf"),
+            concat!(
+                "\"AppState\"\n",
+                "{\n",
+                "  \"appid\"\t\"1030300\"\n",
+                "  \"name\"\t\"Hollow Knight: Silksong\"\n",
+                "  \"StateFlags\"\t\"4\"\n",
+                "}\n"
+            ),
+        );
+
+        let games = installed_games_from_root(&steam_root).expect("failed to load games");
+
+        assert_eq!(
+            games,
+            vec![SteamGame {
+                name: "Hollow Knight: Silksong".to_string(),
+                appid: "1030300".to_string(),
+                last_played: 0,
+                playtime_minutes: 0,
+                install_state: InstallState::Installed,
+                size_on_disk_bytes: 0,
+                launch_options: String::new(),
+            }]
+        );
+    }
+
+    #[test]
+    fn sorts_by_last_played_descending() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        let steam_root = tmp.path().join(".steam");
+        let steamapps = steam_root.join("steam").join("steamapps");
+
+        write_file(
+            &steamapps.join("appmanifest_10.acf"),
+            concat!(
+                "\"AppState\"\n",
+                "{\n",
+                "  \"appid\"\t\"10\"\n",
+                "  \"name\"\t\"Older Game\"\n",
+                "  \"LastPlayed\"\t\"100\"\n",
+                "}\n"
+            ),
+        );
+
+        write_file(
+            &steamapps.join("appmanifest_20.acf"),
+            concat!(
+                "\"AppState\"\n",
+                "{\n",
+                "  \"appid\"\t\"20\"\n",
+                "  \"name\"\t\"Newer Game\"\n",
+                "  \"LastPlayed\"\t\"200\"\n",
+                "}\n"
+            ),
+        );
+
+        let games = installed_games_from_root(&steam_root).expect("failed to load games");
+        let names: Vec<&str> = games.iter().map(|g| g.name.as_str()).collect();
+
+        assert_eq!(names, vec!["Newer Game", "Older Game"]);
+        assert_eq!(games[0].last_played, 200);
+        assert_eq!(games[1].last_played, 100);
+        assert_eq!(games[0].playtime_minutes, 0);
+        assert_eq!(games[1].playtime_minutes, 0);
+    }
+
+    #[test]
+    fn loads_playtime_from_localconfig() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        let steam_root = tmp.path().join(".steam");
+        let steamapps = steam_root.join("steam").join("steamapps");
+        let localconfig = steam_root
+            .join("steam")
+            .join("userdata")
+            .join("123")
+            .join("config")
+            .join("localconfig.vdf");
+
+        write_file(
+            &steamapps.join("appmanifest_20.acf"),
+            concat!(
+                "\"AppState\"\n",
+                "{\n",
+                "  \"appid\"\t\"20\"\n",
+                "  \"name\"\t\"Newer Game\"\n",
+                "  \"LastPlayed\"\t\"200\"\n",
+                "}\n"
+            ),
+        );
+
+        write_file(
+            &localconfig,
+            concat!(
+                "\"UserLocalConfigStore\"\n",
+                "{\n",
+                "  \"Software\"\n",
+                "  {\n",
+                "    \"Valve\"\n",
+                "    {\n",
+                "      \"Steam\"\n",
+                "      {\n",
+                "        \"apps\"\n",
+                "        {\n",
+                "          \"20\"\n",
+                "          {\n",
+                "            \"Playtime\"\t\"321\"\n",
+                "          }\n",
+                "        }\n",
+                "      }\n",
+                "    }\n",
+                "  }\n",
+                "}\n"
+            ),
+        );
+
+        let games = installed_games_from_root(&steam_root).expect("failed to load games");
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].appid, "20");
+        assert_eq!(games[0].playtime_minutes, 321);
+    }
+
+    #[test]
+    fn surfaces_install_state_and_size_from_appmanifest() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        let steam_root = tmp.path().join(".steam");
+        let steamapps = steam_root.join("steam").join("steamapps");
+
+        write_file(
+            &steamapps.join("appmanifest_10.acf"),
+            concat!(
+                "\"AppState\"\n",
+                "{\n",
+                "  \"appid\"\t\"10\"\n",
+                "  \"name\"\t\"Fully Installed Game\"\n",
+                "  \"StateFlags\"\t\"4\"\n",
+                "  \"SizeOnDisk\"\t\"1073741824\"\n",
+                "}\n"
+            ),
+        );
+
+        write_file(
+            &steamapps.join("appmanifest_20.acf"),
+            concat!(
+                "\"AppState\"\n",
+                "{\n",
+                "  \"appid\"\t\"20\"\n",
+                "  \"name\"\t\"Downloading Game\"\n",
+                "  \"StateFlags\"\t\"4\"\n",
+                "  \"SizeOnDisk\"\t\"1073741824\"\n",
+                "  \"BytesDownloaded\"\t\"512\"\n",
+                "  \"BytesToDownload\"\t\"1024\"\n",
+                "}\n"
+            ),
+        );
+
+        write_file(
+            &steamapps.join("appmanifest_30.acf"),
+            concat!(
+                "\"AppState\"\n",
+                "{\n",
+                "  \"appid\"\t\"30\"\n",
+                "  \"name\"\t\"Update Pending Game\"\n",
+                "  \"StateFlags\"\t\"0\"\n",
+                "}\n"
+            ),
+        );
+
+        let games = installed_games_from_root(&steam_root).expect("failed to load games");
+        let by_appid = |appid: &str| games.iter().find(|g| g.appid == appid).unwrap();
+
+        assert_eq!(by_appid("10").install_state, InstallState::Installed);
+        assert_eq!(by_appid("10").size_on_disk_bytes, 1073741824);
+        assert_eq!(by_appid("20").install_state, InstallState::Downloading);
+        assert_eq!(by_appid("30").install_state, InstallState::UpdatePending);
+    }
+
+    #[test]
+    fn honors_launch_options_from_localconfig() {
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        let steam_root = tmp.path().join(".steam");
+        let steamapps = steam_root.join("steam").join("steamapps");
+        let localconfig = steam_root
+            .join("steam")
+            .join("userdata")
+            .join("123")
+            .join("config")
+            .join("localconfig.vdf");
+
+        write_file(
+            &steamapps.join("appmanifest_20.acf"),
+            concat!(
+                "\"AppState\"\n",
+                "{\n",
+                "  \"appid\"\t\"20\"\n",
+                "  \"name\"\t\"Newer Game\"\n",
+                "}\n"
+            ),
+        );
+
+        write_file(
+            &localconfig,
+            concat!(
+                "\"UserLocalConfigStore\"\n",
+                "{\n",
+                "  \"Software\"\n",
+                "  {\n",
+                "    \"Valve\"\n",
+                "    {\n",
+                "      \"Steam\"\n",
+                "      {\n",
+                "        \"apps\"\n",
+                "        {\n",
+                "          \"20\"\n",
+                "          {\n",
+                "            \"LaunchOptions\"\t\"-novid +fps_max 0\"\n",
+                "          }\n",
+                "        }\n",
+                "      }\n",
+                "    }\n",
+                "  }\n",
+                "}\n"
+            ),
+        );
+
+        let games = installed_games_from_root(&steam_root).expect("failed to load games");
+        assert_eq!(games[0].launch_options, "-novid +fps_max 0");
+        assert_eq!(
+            games[0].launch_command(),
+            (
+                String::from("steam"),
+                vec![String::from("steam://run/20//-novid +fps_max 0/")]
+            )
+        );
+    }
+
+    #[test]
+    fn reads_non_steam_shortcuts_from_binary_vdf() {
+        fn push_cstring(bytes: &mut Vec<u8>, s: &str) {
+            bytes.extend_from_slice(s.as_bytes());
+            bytes.push(0);
+        }
+
+        let tmp = tempfile::tempdir().expect("failed to create temp dir");
+        let steam_root = tmp.path().join(".steam");
+        let shortcuts_vdf = steam_root
+            .join("steam")
+            .join("userdata")
+            .join("123")
+            .join("config")
+            .join("shortcuts.vdf");
+
+        let mut bytes = Vec::new();
+        bytes.push(0x00);
+        push_cstring(&mut bytes, "shortcuts");
+        bytes.push(0x00);
+        push_cstring(&mut bytes, "0");
+        bytes.push(0x01);
+        push_cstring(&mut bytes, "AppName");
+        push_cstring(&mut bytes, "RetroArch");
+        bytes.push(0x01);
+        push_cstring(&mut bytes, "Exe");
+        push_cstring(&mut bytes, "/usr/bin/retroarch");
+        bytes.push(0x01);
+        push_cstring(&mut bytes, "StartDir");
+        push_cstring(&mut bytes, "/usr/bin");
+        bytes.push(0x02);
+        push_cstring(&mut bytes, "appid");
+        bytes.extend_from_slice(&12345_i32.to_le_bytes());
+        bytes.push(0x08); // end "0"
+        bytes.push(0x08); // end "shortcuts"
+
+        fs::create_dir_all(shortcuts_vdf.parent().unwrap()).expect("failed to create parent dirs");
+        fs::write(&shortcuts_vdf, &bytes).expect("failed to write shortcuts.vdf");
+
+        let games = installed_games_from_root(&steam_root).expect("failed to load games");
+
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].name, "RetroArch");
+        assert_eq!(games[0].appid, "12345");
+        assert_eq!(
+            games[0].launch_command(),
+            (
+                String::from("steam"),
+                vec![String::from("steam://rungameid/12345")]
+            )
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use indexmap::IndexMap;
+
+/// A decoded node from a binary VDF file (Steam's `shortcuts.vdf` format).
+#[derive(Clone, Debug, PartialEq)]
+pub enum BinVdfValue {
+    Map(IndexMap<String, BinVdfValue>),
+    Str(String),
+    Int(i32),
+}
+
+impl BinVdfValue {
+    pub fn as_map(&self) -> Option<&IndexMap<String, BinVdfValue>> {
+        match self {
+            BinVdfValue::Map(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            BinVdfValue::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i32> {
+        match self {
+            BinVdfValue::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+}
+
+const TAG_MAP: u8 = 0x00;
+const TAG_STR: u8 = 0x01;
+const TAG_INT: u8 = 0x02;
+const TAG_MAP_END: u8 = 0x08;
+
+/// Parses a binary VDF document (e.g. the contents of `shortcuts.vdf`) into a
+/// tree rooted at an implicit top-level map, mirroring [`super::vdf::parse`]
+/// for the text format. Unknown field tags and truncated trailing bytes are
+/// skipped rather than treated as parse errors, since shortcuts.vdf in the
+/// wild is often padded or written by different Steam client versions.
+pub fn parse(bytes: &[u8]) -> IndexMap<String, BinVdfValue> {
+    let mut reader = Reader { bytes, pos: 0 };
+    parse_map(&mut reader)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn next_byte(&mut self) -> Option<u8> {
+        let byte = *self.bytes.get(self.pos)?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    fn read_cstring(&mut self) -> Option<String> {
+        let start = self.pos;
+        while *self.bytes.get(self.pos)? != 0 {
+            self.pos += 1;
+        }
+        let value = String::from_utf8_lossy(&self.bytes[start..self.pos]).into_owned();
+        self.pos += 1;
+        Some(value)
+    }
+
+    fn read_i32(&mut self) -> Option<i32> {
+        let bytes: [u8; 4] = self.bytes.get(self.pos..self.pos + 4)?.try_into().ok()?;
+        self.pos += 4;
+        Some(i32::from_le_bytes(bytes))
+    }
+
+    /// Recovery path for an unrecognized tag: advance past what is hopefully
+    /// a NUL-terminated key so the rest of the map still has a chance to parse.
+    fn skip_to_next_nul(&mut self) {
+        while let Some(byte) = self.bytes.get(self.pos) {
+            self.pos += 1;
+            if *byte == 0 {
+                break;
+            }
+        }
+    }
+}
+
+fn parse_map(reader: &mut Reader) -> IndexMap<String, BinVdfValue> {
+    let mut map = IndexMap::new();
+
+    while let Some(tag) = reader.next_byte() {
+        match tag {
+            TAG_MAP_END => break,
+            TAG_MAP => {
+                let Some(key) = reader.read_cstring() else {
+                    break;
+                };
+                map.insert(key, BinVdfValue::Map(parse_map(reader)));
+            }
+            TAG_STR => {
+                let (Some(key), Some(value)) = (reader.read_cstring(), reader.read_cstring())
+                else {
+                    break;
+                };
+                map.insert(key, BinVdfValue::Str(value));
+            }
+            TAG_INT => {
+                let (Some(key), Some(value)) = (reader.read_cstring(), reader.read_i32()) else {
+                    break;
+                };
+                map.insert(key, BinVdfValue::Int(value));
+            }
+            _ => {
+                reader.skip_to_next_nul();
+                reader.skip_to_next_nul();
+            }
+        }
+    }
+
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_cstring(bytes: &mut Vec<u8>, s: &str) {
+        bytes.extend_from_slice(s.as_bytes());
+        bytes.push(0);
+    }
+
+    #[test]
+    fn parses_a_single_shortcut_entry() {
+        let mut bytes = Vec::new();
+        bytes.push(TAG_MAP);
+        push_cstring(&mut bytes, "shortcuts");
+        bytes.push(TAG_MAP);
+        push_cstring(&mut bytes, "0");
+        bytes.push(TAG_STR);
+        push_cstring(&mut bytes, "AppName");
+        push_cstring(&mut bytes, "Some Emulator");
+        bytes.push(TAG_STR);
+        push_cstring(&mut bytes, "Exe");
+        push_cstring(&mut bytes, "/usr/bin/emu");
+        bytes.push(TAG_INT);
+        push_cstring(&mut bytes, "appid");
+        bytes.extend_from_slice(&42_i32.to_le_bytes());
+        bytes.push(TAG_MAP_END); // end "0"
+        bytes.push(TAG_MAP_END); // end "shortcuts"
+
+        let parsed = parse(&bytes);
+        let shortcut = parsed
+            .get("shortcuts")
+            .and_then(BinVdfValue::as_map)
+            .and_then(|shortcuts| shortcuts.get("0"))
+            .and_then(BinVdfValue::as_map)
+            .expect("expected shortcut entry");
+
+        assert_eq!(
+            shortcut.get("AppName").and_then(BinVdfValue::as_str),
+            Some("Some Emulator")
+        );
+        assert_eq!(shortcut.get("appid").and_then(BinVdfValue::as_int), Some(42));
+    }
+
+    #[test]
+    fn tolerates_unknown_field_tags_and_trailing_bytes() {
+        let mut bytes = Vec::new();
+        bytes.push(TAG_MAP);
+        push_cstring(&mut bytes, "shortcuts");
+        bytes.push(TAG_MAP);
+        push_cstring(&mut bytes, "0");
+        bytes.push(0x07); // unrecognized tag
+        push_cstring(&mut bytes, "tags");
+        push_cstring(&mut bytes, "");
+        bytes.push(TAG_STR);
+        push_cstring(&mut bytes, "AppName");
+        push_cstring(&mut bytes, "Still Parsed");
+        bytes.push(TAG_MAP_END);
+        bytes.push(TAG_MAP_END);
+        bytes.extend_from_slice(&[0xff, 0xff, 0xff]); // trailing garbage
+
+        let parsed = parse(&bytes);
+        let name = parsed
+            .get("shortcuts")
+            .and_then(BinVdfValue::as_map)
+            .and_then(|shortcuts| shortcuts.get("0"))
+            .and_then(BinVdfValue::as_map)
+            .and_then(|entry| entry.get("AppName"))
+            .and_then(BinVdfValue::as_str);
+
+        assert_eq!(name, Some("Still Parsed"));
+    }
+}
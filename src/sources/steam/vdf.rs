@@ -0,0 +1,223 @@
+use indexmap::IndexMap;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// A parsed text-VDF tree: either a nested map or a leaf string value.
+///
+/// Steam's text VDF format is just quoted-string keys/values and `{`/`}` map
+/// delimiters, so this is the whole shape we need to represent it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VdfValue {
+    Map(IndexMap<String, VdfValue>),
+    String(String),
+}
+
+impl VdfValue {
+    pub fn as_map(&self) -> Option<&IndexMap<String, VdfValue>> {
+        match self {
+            VdfValue::Map(map) => Some(map),
+            VdfValue::String(_) => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            VdfValue::String(s) => Some(s),
+            VdfValue::Map(_) => None,
+        }
+    }
+
+    /// Resolves a dot-separated key-path against this value, e.g.
+    /// `"libraryfolders.*.path"`. A `*` segment matches every key at that
+    /// level; keys are matched case-insensitively since Steam isn't
+    /// consistent about casing across its own files.
+    pub fn query(&self, path: &str) -> Vec<&VdfValue> {
+        let segments: Vec<&str> = path.split('.').collect();
+        Self::query_segments(self, &segments)
+    }
+
+    fn query_segments<'a>(value: &'a VdfValue, segments: &[&str]) -> Vec<&'a VdfValue> {
+        let Some((head, rest)) = segments.split_first() else {
+            return vec![value];
+        };
+
+        let Some(map) = value.as_map() else {
+            return Vec::new();
+        };
+
+        map.iter()
+            .filter(|(key, _)| *head == "*" || key.eq_ignore_ascii_case(head))
+            .flat_map(|(_, child)| Self::query_segments(child, rest))
+            .collect()
+    }
+}
+
+/// Parses a text-VDF document into a tree rooted at an implicit top-level map
+/// (the document's own top-level key, e.g. `AppState` or `libraryfolders`,
+/// becomes an entry in that root map rather than being unwrapped away).
+pub fn parse(content: &str) -> VdfValue {
+    let mut tokens = Tokenizer::new(content);
+    VdfValue::Map(parse_map(&mut tokens))
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Str(String),
+    Open,
+    Close,
+}
+
+struct Tokenizer<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(content: &'a str) -> Self {
+        Tokenizer {
+            chars: content.chars().peekable(),
+        }
+    }
+
+    fn next_token(&mut self) -> Option<Token> {
+        loop {
+            match *self.chars.peek()? {
+                c if c.is_whitespace() => {
+                    self.chars.next();
+                }
+                '{' => {
+                    self.chars.next();
+                    return Some(Token::Open);
+                }
+                '}' => {
+                    self.chars.next();
+                    return Some(Token::Close);
+                }
+                '"' => {
+                    self.chars.next();
+                    return Some(Token::Str(self.read_quoted()));
+                }
+                '/' => {
+                    self.chars.next();
+                    if self.chars.peek() == Some(&'/') {
+                        for c in self.chars.by_ref() {
+                            if c == '\n' {
+                                break;
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    // Unknown/stray character outside a quoted string; skip it.
+                    self.chars.next();
+                }
+            }
+        }
+    }
+
+    fn read_quoted(&mut self) -> String {
+        let mut value = String::new();
+        while let Some(c) = self.chars.next() {
+            match c {
+                '"' => break,
+                '\\' => match self.chars.next() {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some(escaped) => value.push(escaped),
+                    None => break,
+                },
+                c => value.push(c),
+            }
+        }
+        value
+    }
+}
+
+fn parse_map(tokens: &mut Tokenizer) -> IndexMap<String, VdfValue> {
+    let mut map = IndexMap::new();
+
+    loop {
+        match tokens.next_token() {
+            Some(Token::Str(key)) => match tokens.next_token() {
+                Some(Token::Open) => {
+                    map.insert(key, VdfValue::Map(parse_map(tokens)));
+                }
+                Some(Token::Str(value)) => {
+                    map.insert(key, VdfValue::String(value));
+                }
+                Some(Token::Close) | None => break,
+            },
+            Some(Token::Open) => {
+                // A map with no preceding key; malformed, but skip past it
+                // rather than giving up on the rest of the document.
+                parse_map(tokens);
+            }
+            Some(Token::Close) | None => break,
+        }
+    }
+
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_nested_maps_regardless_of_layout() {
+        let parsed = parse(concat!(
+            "\"AppState\"\n",
+            "{\n",
+            "  \"appid\"\t\"10\"\n",
+            "  \"name\"\t\"Counter-Strike\"\n",
+            "}\n"
+        ));
+
+        assert_eq!(
+            parsed.query("AppState.appid").first().and_then(|v| v.as_str()),
+            Some("10")
+        );
+        assert_eq!(
+            parsed.query("AppState.name").first().and_then(|v| v.as_str()),
+            Some("Counter-Strike")
+        );
+    }
+
+    #[test]
+    fn parses_values_that_do_not_sit_on_their_own_line() {
+        let parsed = parse("\"AppState\" { \"appid\" \"20\" \"name\" \"Team Fortress Classic\" }");
+
+        assert_eq!(
+            parsed.query("AppState.appid").first().and_then(|v| v.as_str()),
+            Some("20")
+        );
+    }
+
+    #[test]
+    fn unescapes_quoted_string_contents() {
+        let parsed = parse("\"key\" \"line one\\nline two \\\"quoted\\\" \\\\ backslash\"");
+
+        assert_eq!(
+            parsed.query("key").first().and_then(|v| v.as_str()),
+            Some("line one\nline two \"quoted\" \\ backslash")
+        );
+    }
+
+    #[test]
+    fn wildcard_segment_matches_every_key_at_that_level() {
+        let parsed = parse(concat!(
+            "\"libraryfolders\"\n",
+            "{\n",
+            "  \"0\" { \"path\" \"/a\" }\n",
+            "  \"1\" { \"path\" \"/b\" }\n",
+            "}\n"
+        ));
+
+        let paths: Vec<&str> = parsed
+            .query("libraryfolders.*.path")
+            .into_iter()
+            .filter_map(VdfValue::as_str)
+            .collect();
+
+        assert_eq!(paths, vec!["/a", "/b"]);
+    }
+}